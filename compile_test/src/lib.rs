@@ -9,8 +9,12 @@ mod runner;
 pub use crate::parse_fail::ParseFail;
 pub use crate::run_pass::RunPass;
 
+use codespan::{CodeMap, FileMap, FileName};
 use failure::Error;
+use std::fs;
 use std::panic::RefUnwindSafe;
+use std::path::Path;
+use std::sync::Arc;
 
 pub trait TestCase: RefUnwindSafe {
     fn run(&self) -> Outcome;
@@ -25,3 +29,12 @@ pub enum Outcome {
     SetupFail(Error),
     ICE(String),
 }
+
+/// Read a source file from disk, using [`syntax::decode_source`] so things
+/// like a leading byte order mark are handled the same way as the compiler.
+pub(crate) fn load_file(code_map: &mut CodeMap, path: &Path) -> Result<Arc<FileMap>, Error> {
+    let bytes = fs::read(path)?;
+    let src = syntax::decode_source(&bytes).map_err(|diag| failure::err_msg(diag.message))?;
+
+    Ok(code_map.add_filemap(FileName::real(path.to_path_buf()), src))
+}
@@ -47,9 +47,9 @@ impl TestCase for ParseFail {
     fn run(&self) -> Outcome {
         let mut code_map = CodeMap::new();
 
-        let fm = match code_map.add_filemap_from_disk(&self.test_fixture) {
+        let fm = match crate::load_file(&mut code_map, &self.test_fixture) {
             Ok(f) => f,
-            Err(e) => return Outcome::SetupFail(e.into()),
+            Err(e) => return Outcome::SetupFail(e),
         };
 
         match syntax::parse(&fm) {
@@ -25,9 +25,9 @@ impl TestCase for RunPass {
     fn run(&self) -> Outcome {
         let mut code_map = CodeMap::new();
 
-        let fm = match code_map.add_filemap_from_disk(&self.filename) {
+        let fm = match crate::load_file(&mut code_map, &self.filename) {
             Ok(f) => f,
-            Err(e) => return Outcome::SetupFail(e.into()),
+            Err(e) => return Outcome::SetupFail(e),
         };
 
         match Driver::new().run(&fm) {
@@ -1,12 +1,14 @@
 //! Diagnostic reporting.
 
-use codespan::CodeMap;
+use codespan::{ByteSpan, CodeMap};
 use codespan_reporting::termcolor::WriteColor;
-use codespan_reporting::{Diagnostic, Label, Severity};
+use codespan_reporting::{Diagnostic, Label, LabelStyle, Severity};
 use heapsize::HeapSizeOf;
 use serde_derive::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::io;
 use std::mem;
+use std::slice;
 
 /// A collection of zero or more [`codespan_reporting::Diagnostic`] messages.
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -27,6 +29,26 @@ impl Diagnostics {
         self.diags.push(diag);
     }
 
+    pub fn iter(&self) -> slice::Iter<'_, Diagnostic> {
+        self.diags.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.diags.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diags.is_empty()
+    }
+
+    /// The severity of the worst [`Diagnostic`], if there are any.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.diags
+            .iter()
+            .map(|d| d.severity)
+            .max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+    }
+
     /// How many [`Diagnostic`]s are this severe or greater?
     pub fn diagnostics_more_severe_than(&self, severity: Severity) -> usize {
         self.diags.iter().filter(|d| d.severity >= severity).count()
@@ -48,6 +70,23 @@ impl Diagnostics {
     }
 }
 
+impl<'a> IntoIterator for &'a Diagnostics {
+    type Item = &'a Diagnostic;
+    type IntoIter = slice::Iter<'a, Diagnostic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Get the span of a [`Diagnostic`]'s primary label, if it has one.
+pub fn primary_span(diag: &Diagnostic) -> Option<ByteSpan> {
+    diag.labels
+        .iter()
+        .find(|label| label.style == LabelStyle::Primary)
+        .map(|label| label.span)
+}
+
 fn diag_memory_usage(diag: &Diagnostic) -> usize {
     let &Diagnostic {
         severity: _,
@@ -85,3 +124,44 @@ impl<'a> HeapSizeOf for &'a mut Diagnostics {
         0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codespan::ByteIndex;
+
+    #[test]
+    fn an_empty_collection_has_no_max_severity() {
+        let diags = Diagnostics::new();
+
+        assert!(diags.is_empty());
+        assert_eq!(diags.len(), 0);
+        assert_eq!(diags.max_severity(), None);
+    }
+
+    #[test]
+    fn find_the_most_severe_diagnostic() {
+        let mut diags = Diagnostics::new();
+        diags.add(Diagnostic::new_warning("First"));
+        diags.add(Diagnostic::new_error("Second"));
+        diags.add(Diagnostic::new_note("Third"));
+
+        assert_eq!(diags.len(), 3);
+        assert_eq!(diags.max_severity(), Some(Severity::Error));
+
+        let messages: Vec<_> = diags.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(messages, vec!["First", "Second", "Third"]);
+    }
+
+    #[test]
+    fn get_the_primary_span() {
+        let primary = ByteSpan::new(ByteIndex(5), ByteIndex(10));
+        let secondary = ByteSpan::new(ByteIndex(1), ByteIndex(2));
+        let diag = Diagnostic::new_error("Oops")
+            .with_label(Label::new_secondary(secondary))
+            .with_label(Label::new_primary(primary));
+
+        assert_eq!(primary_span(&diag), Some(primary));
+        assert_eq!(primary_span(&Diagnostic::new_error("No labels")), None);
+    }
+}
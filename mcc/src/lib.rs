@@ -6,5 +6,5 @@ mod diagnostics;
 pub mod hir;
 mod trans;
 
-pub use crate::diagnostics::Diagnostics;
+pub use crate::diagnostics::{primary_span, Diagnostics};
 pub use crate::trans::translate;
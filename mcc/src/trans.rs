@@ -12,6 +12,7 @@ use syntax::visitor::{self, Visitor};
 pub fn translate(ast: &File, diagnostics: &mut Diagnostics) -> CompilationUnit {
     let mut trans = Translator::new(diagnostics);
     trans.visit_file(ast);
+    trans.ensure_main_exists();
     trans.comp
 }
 
@@ -37,6 +38,39 @@ impl<'diag> Translator<'diag> {
         );
         self.diags.add(diag);
     }
+
+    fn unsupported_float(&mut self, span: ByteSpan) {
+        let diag = Diagnostic::new_error("Floating point numbers aren't supported yet")
+            .with_label(Label::new_primary(span).with_message("floating point literal"));
+        self.diags.add(diag);
+    }
+
+    fn oversized_integer(&mut self, span: ByteSpan) {
+        let diag = Diagnostic::new_error("Integer literal is too large")
+            .with_label(Label::new_primary(span).with_message("doesn't fit in 64 bits"));
+        self.diags.add(diag);
+    }
+
+    fn check_main_signature(&mut self, decl: &ast::FnDecl) {
+        let return_type = match decl.return_value {
+            ast::Type::Ident(ref id) => id,
+        };
+
+        if return_type.name != "int" {
+            let diag = Diagnostic::new_warning("\"main\" should return an int").with_label(
+                Label::new_primary(return_type.span)
+                    .with_message(format!("declared as returning \"{}\"", return_type.name)),
+            );
+            self.diags.add(diag);
+        }
+    }
+
+    fn ensure_main_exists(&mut self) {
+        if self.comp.lookup("main").is_none() {
+            let diag = Diagnostic::new_error("No \"main\" function was defined");
+            self.diags.add(diag);
+        }
+    }
 }
 
 impl<'diag> Visitor for Translator<'diag> {
@@ -46,25 +80,50 @@ impl<'diag> Visitor for Translator<'diag> {
             return;
         }
 
+        if func.name() == "main" {
+            self.check_main_signature(&func.signature);
+        }
+
         let hir_func = Function {
             node_id: self.hir_ids.next_id(),
             name: func.name().to_string(),
         };
 
         self.comp.add_function(func.node_id(), hir_func);
+        visitor::visit_function(self, func);
+    }
+
+    fn visit_literal(&mut self, lit: &ast::Literal) {
+        match lit.kind {
+            // TODO: Add floating point types to the HIR instead of rejecting them
+            ast::LiteralKind::Float(_) => self.unsupported_float(lit.span()),
+            ast::LiteralKind::OversizedInteger(_) => self.oversized_integer(lit.span()),
+            _ => {}
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use codespan::{FileMap, FileName};
+    use codespan::{ByteIndex, FileMap, FileName};
+    use codespan_reporting::{LabelStyle, Severity};
 
     fn int_main_void() -> FileMap {
         let src = "int main() { return 42; }";
         FileMap::new(FileName::virtual_("int_main_void"), src.to_string())
     }
 
+    fn translate_src(src: &str) -> Diagnostics {
+        let fm = FileMap::new(FileName::virtual_("test"), src.to_string());
+        let ast = syntax::parse(&fm).unwrap();
+        let mut diags = Diagnostics::new();
+
+        translate(&ast, &mut diags);
+
+        diags
+    }
+
     #[test]
     fn translate_int_main_void() {
         let fm = int_main_void();
@@ -83,4 +142,48 @@ mod tests {
         let func = &ast.items[0];
         assert_eq!(got.node_id_mapping[&func.node_id()], main_id);
     }
+
+    #[test]
+    fn an_empty_file_has_no_main() {
+        let diags = translate_src("");
+
+        assert_eq!(diags.len(), 1);
+        let diag = &diags.diagnostics()[0];
+        assert_eq!(diag.severity, Severity::Error);
+        assert_eq!(diag.message, "No \"main\" function was defined");
+    }
+
+    #[test]
+    fn float_literals_are_rejected() {
+        let diags = translate_src("int main() { return 1.5; }");
+
+        assert_eq!(diags.len(), 1);
+        let diag = &diags.diagnostics()[0];
+        assert_eq!(diag.severity, Severity::Error);
+        assert_eq!(diag.message, "Floating point numbers aren't supported yet");
+        assert_eq!(diag.labels.len(), 1);
+        assert_eq!(diag.labels[0].style, LabelStyle::Primary);
+        assert_eq!(diag.labels[0].span, ByteSpan::new(ByteIndex(20), ByteIndex(23)));
+    }
+
+    #[test]
+    fn integer_literals_which_are_too_large_are_rejected() {
+        let diags = translate_src("int main() { return 99999999999999999999; }");
+
+        assert_eq!(diags.len(), 1);
+        let diag = &diags.diagnostics()[0];
+        assert_eq!(diag.severity, Severity::Error);
+        assert_eq!(diag.message, "Integer literal is too large");
+        assert_eq!(diag.labels.len(), 1);
+        assert_eq!(diag.labels[0].style, LabelStyle::Primary);
+        assert_eq!(diag.labels[0].span, ByteSpan::new(ByteIndex(20), ByteIndex(40)));
+    }
+
+    #[test]
+    fn void_main_is_nonstandard() {
+        let diags = translate_src("void main() { return; }");
+
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags.max_severity(), Some(Severity::Warning));
+    }
 }
@@ -7,7 +7,7 @@ extern crate slog;
 
 mod timer;
 
-use codespan::FileMap;
+use codespan::{CodeMap, FileMap, FileName};
 use codespan_reporting::Severity;
 use crate::timer::Timer;
 use mcc::hir::CompilationUnit;
@@ -22,6 +22,7 @@ pub struct Driver {
     logger: Logger,
     timer: Timer,
     diags: Diagnostics,
+    warnings_as_errors: bool,
 }
 
 impl Driver {
@@ -33,10 +34,17 @@ impl Driver {
         Driver {
             timer: Timer::new(&logger),
             diags: Diagnostics::new(),
+            warnings_as_errors: false,
             logger,
         }
     }
 
+    /// Should warnings abort compilation as if they were errors (i.e.
+    /// `-Werror`)?
+    pub fn set_warnings_as_errors(&mut self, warnings_as_errors: bool) {
+        self.warnings_as_errors = warnings_as_errors;
+    }
+
     pub fn run(&mut self, map: &FileMap) -> Result<(), Diagnostics> {
         info!(self.logger, "Started compilation process";
               "filename" => &format_args!("{}", map.name()));
@@ -54,6 +62,46 @@ impl Driver {
         unimplemented!()
     }
 
+    /// Run just the front-end (parsing and translation) so any errors are
+    /// reported, without going on to generate code.
+    ///
+    /// On success, any non-fatal diagnostics (e.g. warnings) are returned.
+    pub fn check(&mut self, map: &FileMap) -> Result<Diagnostics, Diagnostics> {
+        info!(self.logger, "Checking file";
+              "filename" => &format_args!("{}", map.name()));
+
+        self.timer.start("check");
+        let ast = self.parse(map)?;
+        let _hir = self.trans(&ast)?;
+        self.timer.pop();
+
+        Ok(self.swap_diags())
+    }
+
+    /// Check the raw bytes of a source file, adding it to the [`CodeMap`] so
+    /// diagnostics can be rendered afterwards.
+    ///
+    /// This never panics, no matter how malformed the input is. Invalid
+    /// encodings are reported like any other error.
+    pub fn check_bytes(
+        &mut self,
+        code_map: &mut CodeMap,
+        name: FileName,
+        bytes: &[u8],
+    ) -> Result<Diagnostics, Diagnostics> {
+        let src = match syntax::decode_source(bytes) {
+            Ok(src) => src,
+            Err(diag) => {
+                let mut diags = self.swap_diags();
+                diags.add(diag);
+                return Err(diags);
+            }
+        };
+
+        let map = code_map.add_filemap(name, src);
+        self.check(&map)
+    }
+
     fn parse(&mut self, map: &FileMap) -> Result<File, Diagnostics> {
         match syntax::parse(map) {
             Ok(ast) => Ok(ast),
@@ -69,9 +117,10 @@ impl Driver {
     fn trans(&mut self, ast: &File) -> Result<CompilationUnit, Diagnostics> {
         let hir = mcc::translate(ast, &mut self.diags);
 
-        if self.diags.has_errors() {
+        if self.is_fatal(&self.diags) {
             info!(self.logger, "Aborting translation";
-                  "errors" => self.diags.diagnostics_more_severe_than(Severity::Error));
+                  "errors" => self.diags.diagnostics_more_severe_than(Severity::Error),
+                  "warnings-as-errors" => self.warnings_as_errors);
             self.timer.cancel();
             Err(self.swap_diags())
         } else {
@@ -79,7 +128,109 @@ impl Driver {
         }
     }
 
+    fn is_fatal(&self, diags: &Diagnostics) -> bool {
+        diags.has_errors() || (self.warnings_as_errors && diags.has_warnings())
+    }
+
     fn swap_diags(&mut self) -> Diagnostics {
         mem::replace(&mut self.diags, Diagnostics::new())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codespan::{ByteOffset, ByteSpan};
+
+    #[test]
+    fn check_a_valid_program() {
+        let src = "int main() { return 42; }";
+        let fm = FileMap::new(FileName::virtual_("int_main_void"), src.to_string());
+
+        let got = Driver::new().check(&fm).unwrap();
+
+        assert!(got.is_empty());
+    }
+
+    #[test]
+    fn check_a_file_with_a_byte_order_mark() {
+        let mut code_map = CodeMap::new();
+        let src = b"\xEF\xBB\xBFint main() { return 42; }";
+
+        let got = Driver::new()
+            .check_bytes(&mut code_map, FileName::virtual_("bom"), src)
+            .unwrap();
+
+        assert!(got.is_empty());
+    }
+
+    #[test]
+    fn check_reports_translation_errors() {
+        let src = "int main() { return 1; } int main() { return 2; }";
+        let fm = FileMap::new(FileName::virtual_("duplicate_main"), src.to_string());
+
+        let got = Driver::new().check(&fm).unwrap_err();
+
+        assert!(got.has_errors());
+    }
+
+    #[test]
+    fn check_fails_on_warnings_with_werror() {
+        let src = "void main() { return; }";
+        let fm = FileMap::new(FileName::virtual_("void_main"), src.to_string());
+
+        let got = Driver::new().check(&fm).unwrap();
+        assert_eq!(got.len(), 1);
+        assert_eq!(got.max_severity(), Some(Severity::Warning));
+
+        let mut driver = Driver::new();
+        driver.set_warnings_as_errors(true);
+
+        let got = driver.check(&fm).unwrap_err();
+        assert_eq!(got.len(), 1);
+        assert_eq!(got.max_severity(), Some(Severity::Warning));
+    }
+
+    #[test]
+    fn garbage_input_is_reported_instead_of_panicking() {
+        let inputs: &[(&[u8], Option<&str>)] = &[
+            (b"", None),
+            (b"\xFF\xFE", None),
+            (b"int main(", None),
+            (b"}}}", None),
+            (
+                b"int main() { return 99999999999999999999; }",
+                Some("Integer literal is too large"),
+            ),
+        ];
+
+        for &(input, message) in inputs {
+            let mut code_map = CodeMap::new();
+
+            let got = Driver::new()
+                .check_bytes(&mut code_map, FileName::virtual_("fuzz"), input)
+                .unwrap_err();
+
+            assert!(got.has_errors(), "{:?}", input);
+
+            if let Some(message) = message {
+                assert!(got.iter().any(|d| d.message == message), "{:?}", got);
+            }
+        }
+    }
+
+    #[test]
+    fn unparseable_input_gives_a_single_diagnostic() {
+        let fm = FileMap::new(FileName::virtual_("garbage"), "@#$%^&".to_string());
+
+        let got = Driver::new().check(&fm).unwrap_err();
+
+        assert_eq!(got.len(), 1);
+        let diag = &got.diagnostics()[0];
+        assert_eq!(diag.message, "Invalid Token");
+
+        let start = fm.span().start();
+        let first_char = ByteSpan::new(start, start + ByteOffset(1));
+        assert_eq!(mcc::primary_span(diag), Some(first_char));
+    }
+}
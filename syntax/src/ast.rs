@@ -4,6 +4,7 @@ use codespan::ByteSpan;
 use crate::node_id::NodeId;
 use heapsize::HeapSizeOf;
 use heapsize_derive::HeapSizeOf;
+use serde_derive::{Deserialize, Serialize};
 use std::any::Any;
 use std::fmt::Debug;
 use sum_type::*;
@@ -15,7 +16,10 @@ pub trait AstNode: Any + HeapSizeOf + Debug {
 }
 
 /// The result of parsing an entire source file.
-#[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+///
+/// All AST nodes can be serialized (e.g. to JSON) for use by external tools,
+/// keeping their spans and node IDs.
+#[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
 pub struct File {
     pub span: ByteSpan,
     pub node_id: NodeId,
@@ -34,15 +38,16 @@ impl File {
 
 sum_type! {
     /// A top-level item.
-    #[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+    #[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
     pub enum Item {
         Function,
+        #[serde(skip)]
         u32,
     }
 }
 
 /// A function definition.
-#[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+#[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
 pub struct Function {
     pub span: ByteSpan,
     pub node_id: NodeId,
@@ -66,7 +71,7 @@ impl Function {
 }
 
 /// A function signature.
-#[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+#[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
 pub struct FnDecl {
     pub span: ByteSpan,
     pub node_id: NodeId,
@@ -93,7 +98,7 @@ impl FnDecl {
 }
 
 /// An identifier.
-#[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+#[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
 pub struct Ident {
     pub span: ByteSpan,
     pub node_id: NodeId,
@@ -111,7 +116,7 @@ impl Ident {
 }
 
 /// A type.
-#[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+#[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
 pub enum Type {
     Ident(Ident),
 }
@@ -123,7 +128,7 @@ impl From<Ident> for Type {
 }
 
 /// A return statement.
-#[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+#[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
 pub struct Return {
     pub span: ByteSpan,
     pub node_id: NodeId,
@@ -150,24 +155,25 @@ impl Return {
 
 sum_type! {
     /// Any statement.
-    #[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+    #[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
     pub enum Statement {
         Return,
         /// Dummy variant so we can use the `sum_type!()` macro.
+        #[serde(skip)]
         u32,
     }
 }
 
 sum_type! {
     /// Any expression.
-    #[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+    #[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
     pub enum Expression {
         Literal,
         BinaryOp,
     }
 }
 
-#[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+#[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
 pub struct BinaryOp {
     pub span: ByteSpan,
     pub node_id: NodeId,
@@ -176,14 +182,14 @@ pub struct BinaryOp {
     pub kind: BinaryOperator,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, HeapSizeOf)]
+#[derive(Debug, Copy, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
 pub enum BinaryOperator {
     Add,
     Subtract,
 }
 
 /// A single function argument.
-#[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+#[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
 pub struct Argument {
     pub span: ByteSpan,
     pub node_id: NodeId,
@@ -192,7 +198,7 @@ pub struct Argument {
 }
 
 /// A literal value.
-#[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+#[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
 pub struct Literal {
     pub span: ByteSpan,
     pub node_id: NodeId,
@@ -211,14 +217,20 @@ impl Literal {
 
 sum_type! {
     /// The particular kind of literal.
-    #[derive(Debug, Clone, PartialEq, HeapSizeOf)]
+    #[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
     pub enum LiteralKind {
         Float(f64),
         Integer(i64),
         String(String),
+        OversizedInteger,
     }
 }
 
+/// An integer literal which is too large to fit in an `i64`, kept as written
+/// so it can be reported with the rest of the translation errors.
+#[derive(Debug, Clone, PartialEq, HeapSizeOf, Serialize, Deserialize)]
+pub struct OversizedInteger(pub String);
+
 /// Apply the same operation to each variant in an enum.
 ///
 /// # Examples
@@ -302,3 +314,28 @@ impl_ast_node!(Item; Function);
 impl_ast_node!(Statement; Return);
 impl_ast_node!(Expression; Literal, BinaryOp);
 impl_ast_node!(Type; Ident);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codespan::{FileMap, FileName};
+
+    #[test]
+    fn round_trip_through_json() {
+        let src = "int main() { return 5; }";
+        let fm = FileMap::new(FileName::virtual_("int_main_void"), src.to_string());
+        let original = crate::parse(&fm).unwrap();
+        assert!(original.node_id.is_valid());
+
+        let json = serde_json::to_string(&original).unwrap();
+        let got: File = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(got, original);
+    }
+
+    #[test]
+    fn dummy_variants_cant_be_deserialized() {
+        assert!(serde_json::from_str::<Item>(r#"{"u32":0}"#).is_err());
+        assert!(serde_json::from_str::<Statement>(r#"{"u32":0}"#).is_err());
+    }
+}
@@ -1,10 +1,10 @@
 // auto-generated: "lalrpop 0.15.2"
-// sha256: 1b4ba9140243c7269b735f922c31ac57f5bd5bbddd4acef42a28c3e73c68e
+// sha256: 7f431177fcf8eef56edda90e59322654386b5e538cad2364520d432ab95b3
 #![allow(dead_code, rust_2018_idioms, elided_lifetimes_in_paths)]
 use codespan::ByteSpan;
 use std::str::FromStr;
 use crate::ast::{Item, File, Function, FnDecl, Literal, LiteralKind, Expression,
-                 Statement, Return, Ident, Type};
+                 Statement, Return, Ident, Type, OversizedInteger};
 use crate::parse::bs;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
@@ -16,7 +16,7 @@ mod __parse__Expression {
     use codespan::ByteSpan;
     use std::str::FromStr;
     use crate::ast::{Item, File, Function, FnDecl, Literal, LiteralKind, Expression,
-                 Statement, Return, Ident, Type};
+                 Statement, Return, Ident, Type, OversizedInteger};
     use crate::parse::bs;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
@@ -1222,7 +1222,7 @@ mod __parse__File {
     use codespan::ByteSpan;
     use std::str::FromStr;
     use crate::ast::{Item, File, Function, FnDecl, Literal, LiteralKind, Expression,
-                 Statement, Return, Ident, Type};
+                 Statement, Return, Ident, Type, OversizedInteger};
     use crate::parse::bs;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
@@ -2566,7 +2566,7 @@ mod __parse__FnDecl {
     use codespan::ByteSpan;
     use std::str::FromStr;
     use crate::ast::{Item, File, Function, FnDecl, Literal, LiteralKind, Expression,
-                 Statement, Return, Ident, Type};
+                 Statement, Return, Ident, Type, OversizedInteger};
     use crate::parse::bs;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
@@ -3778,7 +3778,7 @@ mod __parse__Item {
     use codespan::ByteSpan;
     use std::str::FromStr;
     use crate::ast::{Item, File, Function, FnDecl, Literal, LiteralKind, Expression,
-                 Statement, Return, Ident, Type};
+                 Statement, Return, Ident, Type, OversizedInteger};
     use crate::parse::bs;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
@@ -5104,7 +5104,7 @@ mod __parse__Literal {
     use codespan::ByteSpan;
     use std::str::FromStr;
     use crate::ast::{Item, File, Function, FnDecl, Literal, LiteralKind, Expression,
-                 Statement, Return, Ident, Type};
+                 Statement, Return, Ident, Type, OversizedInteger};
     use crate::parse::bs;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
@@ -6304,7 +6304,7 @@ mod __parse__Statement {
     use codespan::ByteSpan;
     use std::str::FromStr;
     use crate::ast::{Item, File, Function, FnDecl, Literal, LiteralKind, Expression,
-                 Statement, Return, Ident, Type};
+                 Statement, Return, Ident, Type, OversizedInteger};
     use crate::parse::bs;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
@@ -7538,7 +7538,7 @@ mod __intern_token {
     use codespan::ByteSpan;
     use std::str::FromStr;
     use crate::ast::{Item, File, Function, FnDecl, Literal, LiteralKind, Expression,
-                 Statement, Return, Ident, Type};
+                 Statement, Return, Ident, Type, OversizedInteger};
     use crate::parse::bs;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
@@ -7861,7 +7861,7 @@ fn __action17<
     (_, __0, _): (usize, &'input str, usize),
 ) -> LiteralKind
 {
-    i64::from_str(__0).unwrap().into()
+    i64::from_str(__0).map(Into::into).unwrap_or_else(|_| OversizedInteger(__0.to_string()).into())
 }
 
 #[allow(unused_variables)]
@@ -13,4 +13,4 @@ mod parse;
 pub mod visitor;
 
 pub use self::node_id::NodeId;
-pub use self::parse::parse;
+pub use self::parse::{decode_source, parse};
@@ -1,12 +1,13 @@
 use crate::ast::*;
 use crate::visitor::{self, MutVisitor};
 use heapsize_derive::HeapSizeOf;
+use serde_derive::{Deserialize, Serialize};
 
 /// A unique ID which corresponds to a particular AST node.
 ///
 /// As a special case, `NodeId(0)` is an invalid node ID. This allows it to
 /// be used as a placeholder.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, HeapSizeOf)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, HeapSizeOf, Serialize, Deserialize)]
 pub struct NodeId(u32);
 
 impl NodeId {
@@ -12,7 +12,7 @@ pub fn parse(filemap: &FileMap) -> Result<File, Diagnostic> {
 
     let mut parsed = FileParser::new()
         .parse(filemap.src())
-        .map_err(|e| e.map_location(|l| ByteIndex(l as u32) - base_offset))
+        .map_err(|e| e.map_location(|l| ByteIndex(l as u32) + base_offset))
         .map_err(|e| translate_parse_error(filemap, e))?;
 
     fix_up(&mut parsed, base_offset);
@@ -20,16 +20,35 @@ pub fn parse(filemap: &FileMap) -> Result<File, Diagnostic> {
     Ok(parsed)
 }
 
+/// The UTF-8 *Byte Order Mark* some editors like to put at the start of a
+/// file.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Turn the raw bytes read from a source file into text suitable for
+/// creating a [`FileMap`], stripping any leading byte order mark.
+pub fn decode_source(bytes: &[u8]) -> Result<String, Diagnostic> {
+    let bom_len = if bytes.starts_with(UTF8_BOM) {
+        UTF8_BOM.len()
+    } else {
+        0
+    };
+
+    String::from_utf8(bytes[bom_len..].to_vec()).map_err(|e| {
+        let offset = bom_len + e.utf8_error().valid_up_to();
+        Diagnostic::new_error(format!(
+            "Source file isn't valid UTF-8 (invalid byte at offset {})",
+            offset
+        ))
+    })
+}
+
 fn translate_parse_error(
     filemap: &FileMap,
     err: ParseError<ByteIndex, Token<'_>, &str>,
 ) -> Diagnostic {
-    let base_offset = filemap.span().start() - ByteIndex(0);
-
     match err {
         ParseError::InvalidToken { location } => {
-            let loc = location - base_offset;
-            let span = ByteSpan::new(loc, loc + ByteOffset(1));
+            let span = ByteSpan::new(location, location + ByteOffset(1));
 
             if filemap.span().contains(span) {
                 Diagnostic::new_error("Invalid Token").with_label(Label::new_primary(span))
@@ -49,7 +68,26 @@ fn translate_parse_error(
                 format!("Expected one of {}", expected.join("or"))
             };
 
-            Diagnostic::new_error(msg)
+            let mut diag = Diagnostic::new_error(msg);
+
+            if expected.iter().any(|e| e == r#""}""#) {
+                if let Some(ix) = unclosed_brace(filemap.src()) {
+                    let eof = filemap.span().end();
+                    let open = filemap.span().start() + ByteOffset(ix as i64);
+
+                    diag = diag
+                        .with_label(
+                            Label::new_primary(ByteSpan::new(eof, eof))
+                                .with_message("Expected \"}\""),
+                        )
+                        .with_label(
+                            Label::new_secondary(ByteSpan::new(open, open + ByteOffset(1)))
+                                .with_message("Unclosed \"{\""),
+                        );
+                }
+            }
+
+            diag
         }
         ParseError::UnrecognizedToken {
             token: Some((start, tok, end)),
@@ -76,6 +114,37 @@ fn translate_parse_error(
     }
 }
 
+/// Find the innermost `{` which is never closed, ignoring anything inside a
+/// string literal.
+fn unclosed_brace(src: &str) -> Option<usize> {
+    let mut open = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (ix, c) in src.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '{' => open.push(ix),
+            '}' => {
+                open.pop();
+            }
+            _ => {}
+        }
+    }
+
+    open.pop()
+}
+
 fn fix_up(file: &mut File, _base_offset: ByteOffset) {
     // TODO: Update all span locations
     node_id::assign_node_ids(file);
@@ -88,10 +157,13 @@ pub(crate) fn bs(left: usize, right: usize) -> ByteSpan {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use codespan::FileName;
+    use codespan_reporting::LabelStyle;
     use crate::ast::{
-        Expression, FnDecl, Function, Ident, Item, Literal, LiteralKind, Return, Statement, Type,
+        Expression, FnDecl, Function, Ident, Item, Literal, LiteralKind, OversizedInteger, Return,
+        Statement, Type,
     };
-    use crate::grammar::{FnDeclParser, ItemParser, LiteralParser, StatementParser};
+    use crate::grammar::{FileParser, FnDeclParser, ItemParser, LiteralParser, StatementParser};
 
     #[test]
     fn parse_a_literal() {
@@ -103,6 +175,17 @@ mod tests {
         assert_eq!(got, should_be);
     }
 
+    #[test]
+    fn integer_literals_which_are_too_large_are_kept_as_written() {
+        let src = "99999999999999999999";
+        let kind = LiteralKind::from(OversizedInteger(src.to_string()));
+        let should_be = Literal::new(kind, bs(0, src.len()));
+
+        let got = LiteralParser::new().parse(src).unwrap();
+
+        assert_eq!(got, should_be);
+    }
+
     #[test]
     fn parse_a_bare_return() {
         let src = "return;";
@@ -139,6 +222,71 @@ mod tests {
         assert_eq!(got, should_be);
     }
 
+    #[test]
+    fn decode_a_file_with_a_byte_order_mark() {
+        let src = b"\xEF\xBB\xBFint main() { return 5; }";
+
+        let got = decode_source(src).unwrap();
+
+        assert_eq!(got, "int main() { return 5; }");
+        assert!(FileParser::new().parse(&got).is_ok());
+    }
+
+    #[test]
+    fn decoding_invalid_utf8_is_an_error() {
+        let src = b"int main() { return \xFF; }";
+
+        let got = decode_source(src).unwrap_err();
+
+        assert!(got.message.contains("UTF-8"), "{:?}", got);
+    }
+
+    #[test]
+    fn invalid_utf8_offsets_include_the_byte_order_mark() {
+        let src = b"\xEF\xBB\xBFint \xFF";
+
+        let got = decode_source(src).unwrap_err();
+
+        assert_eq!(
+            got.message,
+            "Source file isn't valid UTF-8 (invalid byte at offset 7)"
+        );
+    }
+
+    #[test]
+    fn point_at_the_unclosed_brace() {
+        let src = "int main() { return 5;";
+        let fm = FileMap::new(FileName::virtual_("unclosed"), src.to_string());
+
+        let got = parse(&fm).unwrap_err();
+
+        assert_eq!(got.labels.len(), 2);
+
+        let expected = got
+            .labels
+            .iter()
+            .find(|label| label.style == LabelStyle::Primary)
+            .unwrap();
+        let eof = fm.span().end();
+        assert_eq!(expected.span, ByteSpan::new(eof, eof));
+        assert_eq!(expected.message.as_ref().unwrap(), "Expected \"}\"");
+
+        let unclosed = got
+            .labels
+            .iter()
+            .find(|label| label.style == LabelStyle::Secondary)
+            .unwrap();
+        let open = fm.span().start() + ByteOffset(11);
+        assert_eq!(unclosed.span, ByteSpan::new(open, open + ByteOffset(1)));
+        assert_eq!(unclosed.message.as_ref().unwrap(), "Unclosed \"{\"");
+    }
+
+    #[test]
+    fn ignore_braces_inside_strings() {
+        assert_eq!(unclosed_brace(r#"{ "{" }"#), None);
+        assert_eq!(unclosed_brace(r#"{ "\"}" "#), Some(0));
+    }
+
     #[test]
     fn parse_int_main_void() {
         let src = r#"